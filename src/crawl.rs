@@ -0,0 +1,49 @@
+use crate::SpeedReaderError;
+use ignore::WalkBuilder;
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+type Result<T> = std::result::Result<T, SpeedReaderError>;
+
+/// Walks a directory tree into an ordered reading queue.
+pub struct Crawl;
+
+impl Crawl {
+    /// Recursively crawl `root`, honoring `.gitignore`/hidden-file rules, and
+    /// return the matching files in a stable, sorted-by-name order so
+    /// "file N/M" and `--resume` land on the same queue across runs.
+    pub fn build_queue(
+        root: &Path,
+        extensions: &HashSet<String>,
+        all_files: bool,
+    ) -> Result<VecDeque<PathBuf>> {
+        let mut queue = VecDeque::new();
+
+        let mut builder = WalkBuilder::new(root);
+        builder.sort_by_file_name(|a, b| a.cmp(b));
+
+        for entry in builder.build() {
+            let entry = entry
+                .map_err(|e| SpeedReaderError::CrawlError(format!("Failed to walk {root:?}: {e}")))?;
+
+            if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+                continue;
+            }
+
+            let path = entry.into_path();
+
+            if all_files || Self::has_allowed_extension(&path, extensions) {
+                queue.push_back(path);
+            }
+        }
+
+        Ok(queue)
+    }
+
+    fn has_allowed_extension(path: &Path, extensions: &HashSet<String>) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| extensions.contains(ext))
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,94 @@
+use super::EvaluationBackend;
+use crate::{LocalModelConfig, SpeedReaderError};
+use llama_cpp_2::{
+    context::params::LlamaContextParams,
+    llama_backend::LlamaBackend,
+    llama_batch::LlamaBatch,
+    model::{params::LlamaModelParams, AddBos, LlamaModel},
+};
+
+type Result<T> = std::result::Result<T, SpeedReaderError>;
+
+/// Evaluates summaries against a local GGUF model via `llama-cpp-2`, for
+/// offline use when shipping text to a cloud API isn't an option.
+pub struct LocalBackend {
+    backend: LlamaBackend,
+    model: LlamaModel,
+}
+
+impl LocalBackend {
+    pub fn new(config: LocalModelConfig) -> Result<Self> {
+        let model_path = config.path.ok_or_else(|| {
+            SpeedReaderError::ConfigError(
+                "local_model.path must be set to use the 'local' backend".to_string(),
+            )
+        })?;
+
+        let backend = LlamaBackend::init()
+            .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to init llama backend: {e}")))?;
+
+        let model = LlamaModel::load_from_file(&backend, model_path, &LlamaModelParams::default())
+            .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to load local model: {e}")))?;
+
+        Ok(Self { backend, model })
+    }
+}
+
+#[async_trait::async_trait]
+impl EvaluationBackend for LocalBackend {
+    async fn evaluate(&self, prompt: String) -> Result<String> {
+        let ctx_params = LlamaContextParams::default();
+        let mut ctx = self
+            .model
+            .new_context(&self.backend, ctx_params)
+            .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to create llama context: {e}")))?;
+
+        let tokens = self
+            .model
+            .str_to_token(&prompt, AddBos::Always)
+            .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to tokenize prompt: {e}")))?;
+
+        let mut batch = LlamaBatch::new(tokens.len(), 1);
+        for (i, token) in tokens.iter().enumerate() {
+            batch
+                .add(*token, i as i32, &[0], i == tokens.len() - 1)
+                .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to add token to batch: {e}")))?;
+        }
+
+        ctx.decode(&mut batch)
+            .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to decode prompt: {e}")))?;
+
+        let mut output = String::new();
+        let mut n_cur = batch.n_tokens();
+        let n_limit = n_cur + 512;
+
+        while n_cur < n_limit {
+            let token = ctx.sample_token(batch.n_tokens() - 1);
+
+            if self.model.is_eog_token(token) {
+                break;
+            }
+
+            let token_str = self
+                .model
+                .token_to_str(token)
+                .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to detokenize token: {e}")))?;
+            output.push_str(&token_str);
+
+            let mut next_batch = LlamaBatch::new(1, 1);
+            next_batch
+                .add(token, n_cur, &[0], true)
+                .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to add token to batch: {e}")))?;
+            ctx.decode(&mut next_batch)
+                .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to decode token: {e}")))?;
+
+            // Advance to the freshly decoded batch so the next sample reads
+            // logits for the token we just generated, continuing
+            // autoregressively instead of re-sampling the same position.
+            batch = next_batch;
+            n_cur += 1;
+        }
+
+        Ok(output)
+    }
+}
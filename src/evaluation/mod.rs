@@ -0,0 +1,34 @@
+mod openrouter;
+
+#[cfg(feature = "local_model")]
+mod local;
+
+pub use openrouter::OpenRouterBackend;
+
+#[cfg(feature = "local_model")]
+pub use local::LocalBackend;
+
+use crate::{Config, Result, SpeedReaderError};
+
+/// A backend capable of evaluating a comprehension prompt and returning the
+/// model's response.
+#[async_trait::async_trait]
+pub trait EvaluationBackend {
+    async fn evaluate(&self, prompt: String) -> Result<String>;
+}
+
+/// Builds the evaluation backend selected by `config.backend`.
+pub fn build_backend(config: &Config) -> Result<Box<dyn EvaluationBackend>> {
+    match config.backend.as_str() {
+        "openrouter" => Ok(Box::new(OpenRouterBackend::new(config.model.clone()))),
+        #[cfg(feature = "local_model")]
+        "local" => Ok(Box::new(LocalBackend::new(config.local_model.clone())?)),
+        #[cfg(not(feature = "local_model"))]
+        "local" => Err(SpeedReaderError::ConfigError(
+            "The 'local' backend requires building with --features local_model".to_string(),
+        )),
+        other => Err(SpeedReaderError::ConfigError(format!(
+            "Unknown evaluation backend: {other}"
+        ))),
+    }
+}
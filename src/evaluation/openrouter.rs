@@ -0,0 +1,104 @@
+use super::EvaluationBackend;
+use crate::{Result, SpeedReaderError};
+use serde::{Deserialize, Serialize};
+use std::env;
+
+const OPEN_ROUTER_URL: &str = "https://openrouter.ai/api/v1/chat/completions";
+
+#[derive(Serialize, Deserialize)]
+struct Message {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OpenRouterBody {
+    model: String,
+    messages: Vec<Message>,
+}
+
+#[derive(Deserialize)]
+struct ApiResponse {
+    choices: Option<Vec<Choice>>,
+}
+
+#[derive(Deserialize)]
+struct Choice {
+    message: Option<Content>,
+}
+
+#[derive(Deserialize)]
+struct Content {
+    content: String,
+}
+
+fn get_api_key() -> Result<String> {
+    let api_key =
+        env::var("OPEN_ROUTER_API_KEY").map_err(|err| SpeedReaderError::EnvVarError(err))?;
+
+    if api_key.trim().is_empty() {
+        eprintln!("Error: OPEN_ROUTER_API_KEY environment variable is set but empty");
+        return Err(SpeedReaderError::EnvVarError(env::VarError::NotPresent));
+    }
+
+    Ok(api_key)
+}
+
+/// Evaluates summaries via OpenRouter's hosted chat completion API.
+pub struct OpenRouterBackend {
+    model: String,
+}
+
+impl OpenRouterBackend {
+    pub fn new(model: String) -> Self {
+        Self { model }
+    }
+}
+
+#[async_trait::async_trait]
+impl EvaluationBackend for OpenRouterBackend {
+    async fn evaluate(&self, prompt: String) -> Result<String> {
+        let api_key = get_api_key()?;
+        let client = reqwest::Client::new();
+
+        let response = client
+            .post(OPEN_ROUTER_URL)
+            .header("Authorization", format!("Bearer {api_key}"))
+            .header("Content-Type", "application/json")
+            .json(&OpenRouterBody {
+                model: self.model.clone(),
+                messages: vec![Message {
+                    role: "user".to_string(),
+                    content: prompt,
+                }],
+            })
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            let data: ApiResponse = response.json().await?;
+
+            match data.choices {
+                Some(choices) if !choices.is_empty() => match choices.into_iter().next() {
+                    Some(Choice {
+                        message: Some(content),
+                    }) => Ok(content.content),
+                    _ => Err(SpeedReaderError::ApiResponseError(
+                        "Missing message content in API response".to_string(),
+                    )),
+                },
+                Some(_) => Err(SpeedReaderError::ApiResponseError(
+                    "Empty choices array in API response".to_string(),
+                )),
+                None => Err(SpeedReaderError::ApiResponseError(
+                    "Missing choices in API response".to_string(),
+                )),
+            }
+        } else {
+            let error_text = response.text().await?;
+            Err(SpeedReaderError::ApiResponseError(format!(
+                "API request failed: {error_text}"
+            )))
+        }
+    }
+}
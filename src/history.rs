@@ -0,0 +1,162 @@
+use crate::SpeedReaderError;
+use dirs::config_dir;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+type Result<T> = std::result::Result<T, SpeedReaderError>;
+
+/// A single completed reading session.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub source: String,
+    pub total_words: usize,
+    pub final_wpm: u64,
+    pub rating: Option<String>,
+}
+
+/// Local reading history: completed sessions plus per-file resume points.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct History {
+    #[serde(default)]
+    entries: Vec<HistoryEntry>,
+
+    #[serde(default)]
+    resume_points: HashMap<String, usize>,
+}
+
+impl History {
+    pub fn load() -> Result<Self> {
+        let path = history_path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let history_str = fs::read_to_string(&path).map_err(|e| {
+            SpeedReaderError::HistoryError(format!("Failed to read history file: {e}"))
+        })?;
+
+        serde_json::from_str(&history_str)
+            .map_err(|e| SpeedReaderError::HistoryError(format!("Failed to parse history file: {e}")))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = history_path()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                SpeedReaderError::HistoryError(format!("Failed to create history directory: {e}"))
+            })?;
+        }
+
+        let history_str = serde_json::to_string_pretty(self)
+            .map_err(|e| SpeedReaderError::HistoryError(format!("Failed to serialize history: {e}")))?;
+
+        fs::write(&path, history_str)
+            .map_err(|e| SpeedReaderError::HistoryError(format!("Failed to write history file: {e}")))
+    }
+
+    /// Records a completed session, dropping any resume point for `source`.
+    pub fn record_session(
+        &mut self,
+        source: String,
+        total_words: usize,
+        final_wpm: u64,
+        rating: Option<String>,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        self.resume_points.remove(&source);
+
+        self.entries.push(HistoryEntry {
+            timestamp,
+            source,
+            total_words,
+            final_wpm,
+            rating,
+        });
+    }
+
+    pub fn set_resume_point(&mut self, source: &str, word_index: usize) {
+        self.resume_points.insert(source.to_string(), word_index);
+    }
+
+    pub fn resume_point(&self, source: &str) -> Option<usize> {
+        self.resume_points.get(source).copied()
+    }
+
+    /// Prints aggregate stats: average WPM, words read this week, and the
+    /// trend of qualitative comprehension ratings over time.
+    pub fn print_stats(&self) {
+        if self.entries.is_empty() {
+            println!("No reading history yet.");
+            return;
+        }
+
+        let average_wpm =
+            self.entries.iter().map(|e| e.final_wpm).sum::<u64>() / self.entries.len() as u64;
+
+        let week_ago = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+            .saturating_sub(7 * 24 * 60 * 60);
+
+        let words_this_week: usize = self
+            .entries
+            .iter()
+            .filter(|e| e.timestamp >= week_ago)
+            .map(|e| e.total_words)
+            .sum();
+
+        println!("Sessions recorded: {}", self.entries.len());
+        println!("Average WPM: {average_wpm}");
+        println!("Words read this week: {words_this_week}");
+
+        let ratings: Vec<&str> = self
+            .entries
+            .iter()
+            .filter_map(|e| e.rating.as_deref())
+            .collect();
+
+        if ratings.is_empty() {
+            println!("Comprehension rating trend: no AI evaluations recorded yet");
+        } else {
+            println!("Comprehension rating trend: {}", ratings.join(" -> "));
+        }
+    }
+}
+
+/// Extracts the qualitative rating (Excellent/Good/Fair/Poor) from an AI
+/// evaluation response, if present.
+pub fn parse_rating(response: &str) -> Option<String> {
+    const RATINGS: [&str; 4] = ["Excellent", "Good", "Fair", "Poor"];
+
+    response.lines().find_map(|line| {
+        RATINGS
+            .iter()
+            .find(|rating| line.contains(*rating))
+            .map(|rating| rating.to_string())
+    })
+}
+
+fn history_path() -> Result<PathBuf> {
+    let mut path = config_dir().ok_or_else(|| {
+        SpeedReaderError::HistoryError("Failed to find config directory".to_string())
+    })?;
+
+    path.push("speedreader");
+    path.push("history.json");
+
+    Ok(path)
+}
@@ -1,5 +1,13 @@
+mod crawl;
+mod evaluation;
+mod history;
+mod tokenizer;
+
 use clap::Parser;
 use console::Term;
+use crawl::Crawl;
+use evaluation::build_backend;
+use history::History;
 use crossterm::{
     cursor,
     event::{self, Event, KeyCode},
@@ -7,13 +15,17 @@ use crossterm::{
 };
 use dirs::config_dir;
 use indicatif::{ProgressBar, ProgressStyle};
+use notify::Watcher;
 use serde::{Deserialize, Serialize};
 use std::{
-    cmp, env,
+    cmp,
+    collections::HashSet,
+    env,
     fmt::Display,
     fs,
     io::{self, Read, Write},
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
     thread, time,
 };
 use thiserror::Error;
@@ -22,6 +34,12 @@ use unicode_width::UnicodeWidthStr;
 const MAX_WPM: u64 = 1000;
 const MIN_WPM: u64 = 150;
 
+/// Current `Config` schema version. Bump this and extend `migrate_config`
+/// whenever a field is added, renamed, or removed, so existing
+/// `config.toml` files upgrade in place instead of silently falling back to
+/// defaults.
+const CONFIG_VERSION: u32 = 3;
+
 #[derive(Debug, Error)]
 enum SpeedReaderError {
     #[error("IO error: {0}")]
@@ -54,6 +72,12 @@ enum SpeedReaderError {
     #[error("Config error: {0}")]
     ConfigError(String),
 
+    #[error("Crawl error: {0}")]
+    CrawlError(String),
+
+    #[error("History error: {0}")]
+    HistoryError(String),
+
     #[error("TOML serialization error: {0}")]
     TomlSerError(#[from] toml::ser::Error),
 
@@ -65,6 +89,10 @@ type Result<T> = std::result::Result<T, SpeedReaderError>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Config {
+    /// Schema version, for migration
+    #[serde(default)]
+    version: u32,
+
     /// Words per minute
     #[serde(default = "default_wpm")]
     wpm: u64,
@@ -77,9 +105,29 @@ struct Config {
     #[serde(default = "default_model")]
     model: String,
 
+    /// Evaluation backend to use: "openrouter" or "local"
+    #[serde(default = "default_backend")]
+    backend: String,
+
+    /// Local model settings, used when `backend = "local"`
+    #[serde(default)]
+    local_model: LocalModelConfig,
+
     /// Keybindings configuration
     #[serde(default)]
     keys: KeyBindings,
+
+    /// Directory crawl settings
+    #[serde(default)]
+    crawl: CrawlConfig,
+
+    /// Tokenization mode: "words", "chunks", or "markdown"
+    #[serde(default = "default_tokenizer")]
+    tokenizer: String,
+}
+
+fn default_tokenizer() -> String {
+    "words".to_string()
 }
 
 fn default_wpm() -> u64 {
@@ -91,6 +139,21 @@ fn default_wpm_step() -> u64 {
 fn default_model() -> String {
     "deepseek/deepseek-r1:free".to_string()
 }
+fn default_backend() -> String {
+    "openrouter".to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct LocalModelConfig {
+    /// Path to a local GGUF model file
+    #[serde(default)]
+    path: Option<String>,
+
+    /// Hugging Face repo the model comes from. Not read by the local backend,
+    /// which loads only from `path` and fails when `path` is unset.
+    #[serde(default)]
+    repo: Option<String>,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct KeyBindings {
@@ -135,6 +198,30 @@ impl Default for KeyBindings {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CrawlConfig {
+    /// File extensions to include when crawling a directory into a reading queue
+    #[serde(default = "default_crawl_extensions")]
+    extensions: HashSet<String>,
+
+    /// Bypass the extension filter and include every file the walker finds
+    #[serde(default)]
+    all_files: bool,
+}
+
+fn default_crawl_extensions() -> HashSet<String> {
+    ["txt", "md"].iter().map(|ext| ext.to_string()).collect()
+}
+
+impl Default for CrawlConfig {
+    fn default() -> Self {
+        CrawlConfig {
+            extensions: default_crawl_extensions(),
+            all_files: false,
+        }
+    }
+}
+
 impl Config {
     fn load() -> Result<Self> {
         let config_path = get_config_path()?;
@@ -151,6 +238,12 @@ impl Config {
 
         let config: Config = toml::from_str(&config_str)?;
 
+        if config.version < CONFIG_VERSION {
+            let config = migrate_config(config);
+            config.save()?;
+            return Ok(config);
+        }
+
         Ok(config)
     }
 
@@ -183,13 +276,46 @@ impl Config {
     }
 }
 
+/// Upgrades an older (or unversioned, pre-`version`-field) `Config` to
+/// [`CONFIG_VERSION`], filling in newly added fields and logging what
+/// changed so users understand why their `config.toml` was rewritten.
+fn migrate_config(mut config: Config) -> Config {
+    if config.version < 1 {
+        println!(
+            "Migrating config.toml: adding schema version tracking (now at version 1)."
+        );
+        config.version = 1;
+    }
+
+    if config.version < 2 {
+        println!(
+            "Migrating config.toml from version 1 to 2: added `backend`/`local_model` evaluation settings and `crawl` directory settings (existing values kept, new ones defaulted)."
+        );
+        config.version = 2;
+    }
+
+    if config.version < 3 {
+        println!(
+            "Migrating config.toml from version 2 to 3: added `tokenizer` setting, defaulted to \"words\"."
+        );
+        config.version = 3;
+    }
+
+    config
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config {
+            version: CONFIG_VERSION,
             wpm: default_wpm(),
             wpm_step: default_wpm_step(),
             model: default_model(),
+            backend: default_backend(),
+            local_model: LocalModelConfig::default(),
             keys: KeyBindings::default(),
+            crawl: CrawlConfig::default(),
+            tokenizer: default_tokenizer(),
         }
     }
 }
@@ -218,6 +344,10 @@ struct Args {
     #[arg(short, long)]
     file: Option<String>,
 
+    /// Path of a directory to recursively crawl into a reading queue
+    #[arg(long)]
+    dir: Option<String>,
+
     /// Words per minute
     #[arg(long)]
     wpm: Option<u64>,
@@ -225,42 +355,26 @@ struct Args {
     /// Generate a default config file
     #[arg(long)]
     init_config: bool,
-}
 
-#[derive(Serialize, Deserialize)]
-struct Message {
-    role: String,
-    content: String,
-}
-
-#[derive(Serialize, Deserialize)]
-struct OpenRouterBody {
-    model: String,
-    messages: Vec<Message>,
-}
-
-#[derive(Deserialize)]
-struct ApiResponse {
-    choices: Option<Vec<Choice>>,
-}
-
-#[derive(Deserialize)]
-struct Choice {
-    message: Option<Content>,
-}
+    /// Print aggregate reading statistics and exit
+    #[arg(long)]
+    stats: bool,
 
-#[derive(Deserialize)]
-struct Content {
-    content: String,
+    /// Resume the given file from the last word you left off on
+    #[arg(long)]
+    resume: bool,
 }
 
 struct ReadResult {
     success: bool,
     wpm: Option<u64>,
+    last_index: usize,
+    total_words: usize,
+    /// Set when the pause key was used to skip to the next file in a
+    /// directory reading queue, rather than to pause/resume in place.
+    skipped: bool,
 }
 
-const OPEN_ROUTER_URL: &str = "https://openrouter.ai/api/v1/chat/completions";
-
 fn print_text<T: Display>(
     text: T,
     position: (u16, u16),
@@ -287,12 +401,6 @@ fn print_text<T: Display>(
     Ok(())
 }
 
-fn tokenize_text(text: &str) -> Vec<String> {
-    text.split_whitespace()
-        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
-        .collect()
-}
-
 fn display_countdown(size: (u16, u16), seconds: u64) -> Result<()> {
     let (columns, rows) = size;
     let countdown = time::Duration::from_secs(seconds);
@@ -326,18 +434,20 @@ fn handle_paused_input(
     current_wpm: &mut u64,
     paused: &mut bool,
     size: (u16, u16),
-    config: &Config,
+    config: &Arc<Mutex<Config>>,
 ) -> Result<Option<ReadResult>> {
     let (columns, rows) = size;
     let mut stdout = io::stdout();
 
+    let pause_key = config.lock().unwrap().keys.pause;
+
     print_text(
         format!(
             "Paused. Press \"{}\" to resume...",
-            if config.keys.pause == ' ' {
+            if pause_key == ' ' {
                 "Spacebar".to_string()
             } else {
-                config.keys.pause.to_string()
+                pause_key.to_string()
             }
         ),
         (columns / 2, rows / 2 + 1),
@@ -346,25 +456,33 @@ fn handle_paused_input(
 
     while *paused {
         if event::poll(time::Duration::from_millis(100))? {
+            // Re-read the config on every key press so edits to `wpm_step` or
+            // remapped keys made while paused take effect immediately.
+            let keys = config.lock().unwrap().keys.clone();
+            let wpm_step = config.lock().unwrap().wpm_step;
+
             match event::read() {
                 Ok(Event::Key(key_code)) => match key_code.code {
-                    KeyCode::Char(c) if c == config.keys.increase_wpm => {
-                        *current_wpm = cmp::min(*current_wpm + config.wpm_step, MAX_WPM);
+                    KeyCode::Char(c) if c == keys.increase_wpm => {
+                        *current_wpm = cmp::min(*current_wpm + wpm_step, MAX_WPM);
                         print_text(format!("WPM: {current_wpm}"), (0, 0), TextAlignment::Left)?;
                     }
-                    KeyCode::Char(c) if c == config.keys.decrease_wpm => {
-                        *current_wpm = cmp::max(*current_wpm - config.wpm_step, MIN_WPM);
+                    KeyCode::Char(c) if c == keys.decrease_wpm => {
+                        *current_wpm = cmp::max(*current_wpm - wpm_step, MIN_WPM);
                         execute!(stdout, cursor::MoveTo(0, 0))?;
                         print!("{}", " ".repeat(format!("WPM: {MAX_WPM}").len()));
                         print_text(format!("WPM: {current_wpm}"), (0, 0), TextAlignment::Left)?;
                     }
-                    KeyCode::Char(c) if c == config.keys.quit => {
+                    KeyCode::Char(c) if c == keys.quit => {
                         return Ok(Some(ReadResult {
                             success: false,
                             wpm: None,
+                            last_index: 0,
+                            total_words: 0,
+                            skipped: false,
                         }));
                     }
-                    KeyCode::Char(c) if c == config.keys.pause => {
+                    KeyCode::Char(c) if c == keys.pause => {
                         *paused = false;
                         execute!(stdout, terminal::Clear(terminal::ClearType::CurrentLine))?;
                     }
@@ -386,6 +504,7 @@ fn display_word_ui(
     current_wpm: u64,
     size: (u16, u16),
     config: &Config,
+    file_progress: Option<(usize, usize)>,
 ) -> Result<()> {
     let mut stdout = io::stdout();
     let (columns, rows) = size;
@@ -394,6 +513,14 @@ fn display_word_ui(
 
     print_text(format!("WPM: {current_wpm}"), (0, 0), TextAlignment::Left)?;
 
+    if let Some((current_file, total_files)) = file_progress {
+        print_text(
+            format!("File {current_file} / {total_files}"),
+            (columns / 2, 0),
+            TextAlignment::Center,
+        )?;
+    }
+
     print_text(
         format!(
             "Word {current_word_index} / {total}",
@@ -425,33 +552,71 @@ fn display_word_ui(
     Ok(())
 }
 
-fn speed_read(buf: &String, config: &Config, size: (u16, u16)) -> Result<ReadResult> {
-    let mut current_wpm = config.wpm;
+fn speed_read(
+    buf: &String,
+    config: &Arc<Mutex<Config>>,
+    size: (u16, u16),
+    file_progress: Option<(usize, usize)>,
+    start_index: usize,
+) -> Result<ReadResult> {
+    // In a directory queue (file_progress is Some), pause skips to the next
+    // file instead of pausing in place.
+    let in_queue = file_progress.is_some();
+    let mut current_wpm = config.lock().unwrap().wpm;
     let (columns, rows) = size;
 
     display_countdown(size, 3)?;
 
     let mut paused = false;
 
-    let words = tokenize_text(buf);
-
-    for (i, word) in words.iter().enumerate() {
-        display_word_ui(word, i, words.len(), current_wpm, size, config)?;
-
-        let dur_wpm = time::Duration::from_millis(60_000 / current_wpm);
+    let tokenizer_mode = config.lock().unwrap().tokenizer.clone();
+    let tokens = tokenizer::tokenize_text(buf, &tokenizer_mode);
+
+    for (i, token) in tokens.iter().enumerate().skip(start_index) {
+        // Snapshot the config once per token so a live edit to keybindings is
+        // picked up on the very next token without holding the lock.
+        let cfg = config.lock().unwrap().clone();
+
+        display_word_ui(
+            &token.text,
+            i,
+            tokens.len(),
+            current_wpm,
+            size,
+            &cfg,
+            file_progress,
+        )?;
+
+        let base_dur_ms = 60_000 / current_wpm;
+        let dur_wpm =
+            time::Duration::from_millis((base_dur_ms as f64 * token.dwell_multiplier) as u64);
         let start = time::Instant::now();
 
         while start.elapsed().as_millis() < dur_wpm.as_millis() {
             if event::poll(time::Duration::from_millis(50))? {
                 match event::read() {
                     Ok(Event::Key(key_code)) => match key_code.code {
-                        KeyCode::Char(c) if c == config.keys.quit => {
+                        KeyCode::Char(c) if c == cfg.keys.quit => {
                             return Ok(ReadResult {
                                 success: false,
                                 wpm: None,
+                                last_index: i,
+                                total_words: tokens.len(),
+                                skipped: false,
+                            });
+                        }
+                        // In a directory queue, pause means "skip to the next
+                        // file" rather than pause in place.
+                        KeyCode::Char(c) if c == cfg.keys.pause && in_queue => {
+                            return Ok(ReadResult {
+                                success: true,
+                                wpm: Some(current_wpm),
+                                last_index: i,
+                                total_words: tokens.len(),
+                                skipped: true,
                             });
                         }
-                        KeyCode::Char(c) if c == config.keys.pause => {
+                        KeyCode::Char(c) if c == cfg.keys.pause => {
                             paused = !paused;
                         }
                         _ => continue,
@@ -466,9 +631,13 @@ fn speed_read(buf: &String, config: &Config, size: (u16, u16)) -> Result<ReadRes
                     handle_paused_input(&mut current_wpm, &mut paused, size, config)?
                 {
                     if result.success && result.wpm.is_some() {
-                        return speed_read(buf, config, (columns, rows));
+                        return speed_read(buf, config, (columns, rows), file_progress, i);
                     }
-                    return Ok(result);
+                    return Ok(ReadResult {
+                        last_index: i,
+                        total_words: tokens.len(),
+                        ..result
+                    });
                 }
             }
         }
@@ -477,6 +646,9 @@ fn speed_read(buf: &String, config: &Config, size: (u16, u16)) -> Result<ReadRes
     Ok(ReadResult {
         success: true,
         wpm: Some(current_wpm),
+        last_index: tokens.len(),
+        total_words: tokens.len(),
+        skipped: false,
     })
 }
 
@@ -510,94 +682,29 @@ Provide:
     )
 }
 
-fn get_api_key() -> Result<String> {
-    let api_key =
-        env::var("OPEN_ROUTER_API_KEY").map_err(|err| SpeedReaderError::EnvVarError(err))?;
-
-    if api_key.trim().is_empty() {
-        eprintln!("Error: OPEN_ROUTER_API_KEY environment variable is set but empty");
-        return Err(SpeedReaderError::EnvVarError(env::VarError::NotPresent));
-    }
-
-    Ok(api_key)
-}
-
-async fn send_evaluation_request(
-    client: &reqwest::Client,
-    message: Message,
-    api_key: &str,
-    model: &str,
-    progress_bar: &ProgressBar,
-) -> Result<String> {
-    let response = client
-        .post(OPEN_ROUTER_URL)
-        .header("Authorization", format!("Bearer {api_key}"))
-        .header("Content-Type", "application/json")
-        .json(&OpenRouterBody {
-            model: model.to_string(),
-            messages: vec![message],
-        })
-        .send()
-        .await?;
-
-    if response.status().is_success() {
-        progress_bar.set_message("Parsing AI response...");
-        let data: ApiResponse = response.json().await?;
-
-        let ai_response = match &data.choices {
-            Some(choices) if !choices.is_empty() => match &choices[0].message {
-                Some(message) => message.content.clone(),
-                None => {
-                    return Err(SpeedReaderError::ApiResponseError(
-                        "Missing message content in API response".to_string(),
-                    ));
-                }
-            },
-            Some(_) => {
-                return Err(SpeedReaderError::ApiResponseError(
-                    "Empty choices array in API response".to_string(),
-                ));
-            }
-            None => {
-                return Err(SpeedReaderError::ApiResponseError(
-                    "Missing choices in API response".to_string(),
-                ));
-            }
-        };
-
-        Ok(ai_response)
-    } else {
-        let error_text = response.text().await?;
-        Err(SpeedReaderError::ApiResponseError(format!(
-            "API request failed: {error_text}"
-        )))
-    }
-}
-
 #[tokio::main]
-async fn process_summary(summary: String, text: String, wpm: u64, config: &Config) -> Result<()> {
+async fn process_summary(
+    summary: String,
+    text: String,
+    wpm: u64,
+    config: &Config,
+) -> Result<String> {
     let pb = ProgressBar::new_spinner();
     pb.enable_steady_tick(time::Duration::from_millis(120));
     pb.set_style(ProgressStyle::default_spinner().template("{spinner:.blue} {msg}")?);
     pb.set_message("\nSetting up evaluation...");
 
-    let api_key = get_api_key()?;
-
-    let client = reqwest::Client::new();
+    let backend = build_backend(config)?;
 
     let prompt = create_evaluation_prompt(&summary, &text, wpm);
-    let message = Message {
-        role: "user".to_string(),
-        content: prompt,
-    };
 
     pb.set_message("Sending request to AI for evaluation...");
 
-    match send_evaluation_request(&client, message, &api_key, &config.model, &pb).await {
+    match backend.evaluate(prompt).await {
         Ok(ai_response) => {
             pb.finish_with_message("AI analysis complete!");
             println!("{ai_response}");
-            Ok(())
+            Ok(ai_response)
         }
         Err(e) => {
             pb.finish_with_message("API request failed!");
@@ -607,6 +714,148 @@ async fn process_summary(summary: String, text: String, wpm: u64, config: &Confi
     }
 }
 
+/// Crawls `dir_path` into a reading queue and speed reads each file in turn.
+///
+/// Quitting ends the whole session immediately; finishing a file moves on to
+/// the next one in the queue. When `resume` is set, each file picks up from
+/// its own last resume point instead of starting over.
+fn run_queue(dir_path: &str, config: &Arc<Mutex<Config>>, resume: bool) -> Result<()> {
+    let root = Path::new(dir_path);
+
+    if !root.exists() {
+        eprintln!("Error: The directory '{}' does not exist.", dir_path);
+        return Err(SpeedReaderError::FileNotFound(dir_path.to_string()));
+    }
+
+    let crawl_config = config.lock().unwrap().crawl.clone();
+    let queue = Crawl::build_queue(root, &crawl_config.extensions, crawl_config.all_files)?;
+
+    if queue.is_empty() {
+        eprintln!("Error: No matching files found in '{}'.", dir_path);
+        return Err(SpeedReaderError::FileNotFound(dir_path.to_string()));
+    }
+
+    let total = queue.len();
+    let size = terminal::size()?;
+    let mut stdout = io::stdout();
+
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+    execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+    execute!(stdout, cursor::Hide)?;
+    terminal::enable_raw_mode()?;
+
+    let mut history = History::load()?;
+
+    let run_result = (|| -> Result<()> {
+        for (i, path) in queue.iter().enumerate() {
+            let text_buf = match fs::read_to_string(path) {
+                Ok(text_buf) => text_buf,
+                Err(e) => {
+                    eprintln!("Error: Skipping '{}': {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            if text_buf.trim().is_empty() {
+                continue;
+            }
+
+            let source = path.display().to_string();
+            let start_index = if resume {
+                history.resume_point(&source).unwrap_or(0)
+            } else {
+                0
+            };
+            let result = speed_read(&text_buf, config, size, Some((i + 1, total)), start_index)?;
+
+            if !result.success {
+                history.set_resume_point(&source, result.last_index);
+                history.save()?;
+                break;
+            }
+
+            // A skip (pause pressed in queue mode) only got the reader to
+            // `last_index`, not through the whole file, so it isn't a
+            // completed session and must not inflate `--stats` with
+            // `total_words` the reader never actually saw.
+            if result.skipped {
+                history.set_resume_point(&source, result.last_index);
+                history.save()?;
+                continue;
+            }
+
+            if let Some(wpm) = result.wpm {
+                history.record_session(source, result.total_words, wpm, None);
+                history.save()?;
+            }
+        }
+
+        Ok(())
+    })();
+
+    terminal::disable_raw_mode()?;
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+    execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+    execute!(stdout, cursor::MoveTo(0, 0))?;
+    execute!(stdout, cursor::Show)?;
+
+    run_result
+}
+
+/// Watches `config.toml` for edits and reloads the shared config in place so
+/// a running session picks up changes without a restart.
+///
+/// The returned watcher must be kept alive for the lifetime of the session;
+/// dropping it stops the filesystem watch.
+fn spawn_config_watcher(shared_config: Arc<Mutex<Config>>) -> Result<notify::RecommendedWatcher> {
+    let config_path = get_config_path()?;
+    let watch_dir = config_path
+        .parent()
+        .ok_or_else(|| {
+            SpeedReaderError::ConfigError("Config path has no parent directory".to_string())
+        })?
+        .to_path_buf();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let event = match res {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("Config watcher error: {e}");
+                return;
+            }
+        };
+
+        // Editors and atomic saves write a temp file and rename it over
+        // config.toml, which surfaces as a Create (not Modify) event, and on
+        // inotify-backed platforms invalidates a watch on the file itself.
+        // Watching the parent directory and filtering by path survives that.
+        let is_relevant_event = matches!(
+            event.kind,
+            notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+        );
+
+        if !is_relevant_event || !event.paths.iter().any(|path| path == &config_path) {
+            return;
+        }
+
+        match Config::load() {
+            Ok(new_config) => {
+                *shared_config.lock().unwrap() = new_config;
+            }
+            Err(e) => eprintln!("Failed to reload config: {e}"),
+        }
+    })
+    .map_err(|e| SpeedReaderError::ConfigError(format!("Failed to start config watcher: {e}")))?;
+
+    watcher
+        .watch(&watch_dir, notify::RecursiveMode::NonRecursive)
+        .map_err(|e| {
+            SpeedReaderError::ConfigError(format!("Failed to watch config directory: {e}"))
+        })?;
+
+    Ok(watcher)
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
 
@@ -620,7 +869,17 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let config = Config::from_args(&args)?;
+    if args.stats {
+        History::load()?.print_stats();
+        return Ok(());
+    }
+
+    let config = Arc::new(Mutex::new(Config::from_args(&args)?));
+    let _config_watcher = spawn_config_watcher(Arc::clone(&config))?;
+
+    if let Some(dir_path) = args.dir.as_ref() {
+        return run_queue(dir_path, &config, args.resume);
+    }
 
     let mut text_buf = String::new();
 
@@ -648,6 +907,18 @@ fn main() -> Result<()> {
         ));
     }
 
+    let source = args
+        .file
+        .clone()
+        .unwrap_or_else(|| "<stdin>".to_string());
+
+    let mut history = History::load()?;
+    let start_index = if args.resume {
+        history.resume_point(&source).unwrap_or(0)
+    } else {
+        0
+    };
+
     let size = terminal::size()?;
     let mut stdout = io::stdout();
 
@@ -657,7 +928,7 @@ fn main() -> Result<()> {
     terminal::enable_raw_mode()?;
 
     let run_result = (|| {
-        let result = speed_read(&text_buf, &config, size)?;
+        let result = speed_read(&text_buf, &config, size, None, start_index)?;
         Ok(result)
     })();
 
@@ -677,38 +948,50 @@ fn main() -> Result<()> {
 
     match run_result {
         Ok(result) => {
-            if result.success {
-                if let Some(wpm) = result.wpm {
-                    println!("Please enter your summary of the text. Press Enter to finish.");
-                    println!("Enter your summary below:");
+            if !result.success {
+                history.set_resume_point(&source, result.last_index);
+                history.save()?;
+                return Ok(());
+            }
 
-                    let term = Term::stdout();
-                    let mut summary_buf = String::new();
+            if let Some(wpm) = result.wpm {
+                println!("Please enter your summary of the text. Press Enter to finish.");
+                println!("Enter your summary below:");
 
-                    loop {
-                        let line = term.read_line()?;
-                        let line = line.trim_end();
+                let term = Term::stdout();
+                let mut summary_buf = String::new();
 
-                        if line.is_empty() {
-                            break;
-                        }
-
-                        summary_buf.push_str(line);
-                        summary_buf.push('\n');
-                    }
+                loop {
+                    let line = term.read_line()?;
+                    let line = line.trim_end();
 
-                    if summary_buf.trim().is_empty() {
-                        println!("No summary provided. Exiting.");
-                        return Ok(());
+                    if line.is_empty() {
+                        break;
                     }
 
-                    if text_buf.trim().is_empty() {
-                        println!("No summary provided. Exiting.");
-                        return Ok(());
-                    }
+                    summary_buf.push_str(line);
+                    summary_buf.push('\n');
+                }
 
-                    process_summary(summary_buf, text_buf, wpm, &config)?;
+                if summary_buf.trim().is_empty() || text_buf.trim().is_empty() {
+                    history.record_session(source, result.total_words, wpm, None);
+                    history.save()?;
+                    println!("No summary provided. Exiting.");
+                    return Ok(());
                 }
+
+                let config_snapshot = config.lock().unwrap().clone();
+                let ai_response = process_summary(summary_buf, text_buf, wpm, &config_snapshot);
+
+                // Record the session regardless of whether the AI evaluation
+                // call succeeded, so a backend error (expected for offline
+                // users or a misconfigured `local` backend) doesn't lose a
+                // fully completed reading session's words/WPM.
+                let rating = ai_response.as_ref().ok().and_then(|r| history::parse_rating(r));
+                history.record_session(source, result.total_words, wpm, rating);
+                history.save()?;
+
+                ai_response?;
             }
             Ok(())
         }
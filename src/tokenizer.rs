@@ -0,0 +1,118 @@
+/// A single unit of text to display in the speed-reading loop, paired with a
+/// dwell multiplier applied to the base per-word duration (e.g. a longer
+/// pause after sentence-ending punctuation).
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub text: String,
+    pub dwell_multiplier: f64,
+}
+
+impl Token {
+    fn new(text: impl Into<String>, dwell_multiplier: f64) -> Self {
+        Token {
+            text: text.into(),
+            dwell_multiplier,
+        }
+    }
+}
+
+/// Tokenizes `text` according to `mode` ("words", "chunks", or "markdown"),
+/// producing the token stream `speed_read`'s timing loop consumes. Unknown
+/// modes fall back to "words".
+pub fn tokenize_text(text: &str, mode: &str) -> Vec<Token> {
+    match mode {
+        "chunks" => tokenize_chunks(text),
+        "markdown" => tokenize_markdown(text),
+        _ => tokenize_words(text),
+    }
+}
+
+/// Punctuation-preserving word mode: keeps trailing `.,;:!?` attached to the
+/// word and adds a longer pause after sentence-ending punctuation.
+fn tokenize_words(text: &str) -> Vec<Token> {
+    text.split_whitespace()
+        .map(|word| Token::new(word, sentence_end_dwell(word)))
+        .collect()
+}
+
+fn sentence_end_dwell(word: &str) -> f64 {
+    if word.ends_with(['.', '!', '?']) {
+        1.6
+    } else if word.ends_with([',', ';', ':']) {
+        1.2
+    } else {
+        1.0
+    }
+}
+
+/// Groups 2-3 short words into a single flashcard, so e.g. "of the" reads as
+/// one beat instead of two.
+fn tokenize_chunks(text: &str) -> Vec<Token> {
+    const MAX_CHUNK_WORDS: usize = 3;
+    const SHORT_WORD_LEN: usize = 4;
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        let mut chunk = vec![words[i]];
+        i += 1;
+
+        while chunk.len() < MAX_CHUNK_WORDS
+            && i < words.len()
+            && words[i - 1].len() <= SHORT_WORD_LEN
+            && words[i].len() <= SHORT_WORD_LEN
+        {
+            chunk.push(words[i]);
+            i += 1;
+        }
+
+        let dwell = 1.0 + 0.3 * (chunk.len() - 1) as f64;
+        tokens.push(Token::new(chunk.join(" "), dwell));
+    }
+
+    tokens
+}
+
+/// Recognizes markdown structure: skips fenced code blocks and emphasizes
+/// headings with a longer dwell, so reading structured notes stays legible.
+fn tokenize_markdown(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut in_code_fence = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            in_code_fence = !in_code_fence;
+            continue;
+        }
+
+        if in_code_fence || trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(heading) = trimmed
+            .strip_prefix("### ")
+            .or_else(|| trimmed.strip_prefix("## "))
+            .or_else(|| trimmed.strip_prefix("# "))
+        {
+            for word in heading.split_whitespace() {
+                tokens.push(Token::new(word.to_uppercase(), 2.0));
+            }
+            continue;
+        }
+
+        let item = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .unwrap_or(trimmed);
+
+        for word in item.split_whitespace() {
+            tokens.push(Token::new(word, sentence_end_dwell(word)));
+        }
+    }
+
+    tokens
+}